@@ -17,15 +17,35 @@
 //!
 //! Arguments:
 //!
-//! VALUE, Temperature value to convert
+//! VALUE, Temperature value to convert; reads whitespace-separated values
+//! from standard input if omitted
 //!
 //! Options:
-//!   
+//!
 //!   -u, --unit
-//!           Temperature unit of the provided value (Celsius, Fahrenheit, or Kelvin)
+//!           Temperature unit of the provided value (Celsius, Fahrenheit,
+//!           Kelvin, Rankine, Réaumur, or Delisle)
 //!
 //!   -c, --convert
-//!           Target temperature unit to convert the value to (Celsius, Fahrenheit, or Kelvin)
+//!           Target temperature unit to convert the value to (Celsius,
+//!           Fahrenheit, Kelvin, Rankine, Réaumur, or Delisle)
+//!
+//!   -a, --all
+//!           Convert the value to every supported temperature scale at once
+//!
+//!       --stdin
+//!           Read whitespace/newline-separated values from standard input
+//!           instead of the VALUE argument
+//!
+//!       --precision <PRECISION>
+//!           Number of digits to display after the decimal point
+//!
+//!       --round
+//!           Round the result to the nearest whole degree instead of using
+//!           --precision
+//!
+//!       --from-sensor
+//!           Read live readings from Linux thermal zones instead of VALUE
 //!
 //!   -h, --help
 //!           Print help (see a summary with '-h')
@@ -37,12 +57,18 @@
 /// Constant/helpers
 pub mod utils;
 
+/// Public temperature conversion API
+pub mod temperature;
+
+/// Linux thermal sensor readings
+pub mod sensors;
+
 use std::error::Error;
+use std::io::{self, Read};
 
-use crate::utils::{
-    ABS_ZERO_CELSIUS, ABS_ZERO_FAHRENHEIT, ABS_ZERO_KELVIN, COLOR_GREEN, COLOR_RESET,
-};
-use clap::Parser;
+use crate::temperature::Temperature;
+use crate::utils::{COLOR_ERROR, COLOR_GREEN, COLOR_RESET};
+use clap::{Parser, ValueEnum};
 
 /// Tempeature unit
 #[derive(clap::ValueEnum, Clone, Debug)]
@@ -55,62 +81,68 @@ enum Unit {
 
     #[value(alias = "kelvin")]
     K,
-}
 
-impl Unit {
-    /// Unit absolute zero value
-    fn absolute_zero(&self) -> f64 {
-        match self {
-            Unit::C => ABS_ZERO_CELSIUS,
-            Unit::F => ABS_ZERO_FAHRENHEIT,
-            Unit::K => ABS_ZERO_KELVIN,
-        }
-    }
+    #[value(alias = "rankine")]
+    Ra,
 
-    fn full_name(&self) -> &str {
-        match self {
-            Unit::C => "Celsius",
-            Unit::F => "Fahrenheit",
-            Unit::K => "Kelvin",
-        }
-    }
+    #[value(alias = "reaumur")]
+    Re,
+
+    #[value(alias = "delisle")]
+    De,
+}
 
-    /// Convert a temperature value from the current unit to Celsius
-    fn to_celsius(&self, value: f64) -> f64 {
+impl Unit {
+    /// Wrap a raw value as the [`Temperature`] variant this unit represents
+    fn to_temperature(&self, value: f64) -> Temperature {
         match self {
-            Unit::C => value,
-            Unit::F => (value - 32.0) * 5.0 / 9.0,
-            Unit::K => value - 273.15,
+            Unit::C => Temperature::Celsius(value),
+            Unit::F => Temperature::Fahrenheit(value),
+            Unit::K => Temperature::Kelvin(value),
+            Unit::Ra => Temperature::Rankine(value),
+            Unit::Re => Temperature::Reaumur(value),
+            Unit::De => Temperature::Delisle(value),
         }
     }
 
-    /// Convert a temperature value from Celsius to the current unit
-    fn from_celsius(&self, celsius: f64) -> f64 {
+    /// Convert a [`Temperature`] to the scale this unit represents
+    fn from_temperature(&self, source: &Temperature) -> Temperature {
         match self {
-            Unit::C => celsius,
-            Unit::F => (celsius * 9.0 / 5.0) + 32.0,
-            Unit::K => celsius + 273.15,
+            Unit::C => source.to_celsius(),
+            Unit::F => source.to_fahrenheit(),
+            Unit::K => source.to_kelvin(),
+            Unit::Ra => source.to_rankine(),
+            Unit::Re => source.to_reaumur(),
+            Unit::De => source.to_delisle(),
         }
     }
 }
 
-/// Converts temperature values between Celsius, Fahrenheit, and Kelvin
+/// Converts temperature values between Celsius, Fahrenheit, Kelvin, Rankine,
+/// Réaumur, and Delisle
 #[derive(Parser, Debug)]
 #[command(
     version,
-    about = "Convert temperatures between Celsius, Fahrenheit, and Kelvin.",
-    long_about = "Converts temperature values between Celsius, Fahrenheit, and Kelvin."
+    about = "Convert temperatures between Celsius, Fahrenheit, Kelvin, Rankine, Réaumur, \
+             and Delisle.",
+    long_about = "Converts temperature values between Celsius, Fahrenheit, Kelvin, Rankine, \
+                  Réaumur, and Delisle. Supports converting to every scale at once (--all), \
+                  reading values from standard input or live Linux thermal sensors, and \
+                  controlling output precision or rounding."
 )]
 pub struct Args {
-    /// Temperature value to convert
+    /// Temperature value to convert; reads whitespace-separated values from
+    /// standard input if omitted
     #[arg(allow_hyphen_values = true)]
-    value: f64,
+    value: Option<f64>,
 
-    /// Temperature unit of the provided value (Celsius, Fahrenheit, or Kelvin)
+    /// Temperature unit of the provided value (Celsius, Fahrenheit, Kelvin,
+    /// Rankine, Réaumur, or Delisle)
     #[arg(short = 'u', long = "unit", ignore_case = true, default_value = "f")]
     value_unit: Unit,
 
-    /// Target temperature unit to convert the value to (Celsius, Fahrenheit, or Kelvin)
+    /// Target temperature unit to convert the value to (Celsius, Fahrenheit,
+    /// Kelvin, Rankine, Réaumur, or Delisle)
     #[arg(
         short = 'c',
         long = "convert",
@@ -119,202 +151,311 @@ pub struct Args {
         default_value = "c"
     )]
     convert: Unit,
+
+    /// Convert the value to every supported temperature scale at once
+    #[arg(short = 'a', long = "all", conflicts_with = "convert")]
+    all: bool,
+
+    /// Read whitespace/newline-separated values from standard input instead
+    /// of the VALUE argument
+    #[arg(long = "stdin")]
+    stdin: bool,
+
+    /// Number of digits to display after the decimal point
+    #[arg(long = "precision", default_value_t = 2, conflicts_with = "round")]
+    precision: usize,
+
+    /// Round the result to the nearest whole degree instead of using --precision
+    #[arg(long = "round")]
+    round: bool,
+
+    /// Read live readings from Linux thermal zones instead of VALUE
+    #[arg(long = "from-sensor", conflicts_with_all = ["stdin", "value"])]
+    from_sensor: bool,
 }
 
 impl Args {
     /// Run/execute command line arguments
     pub fn run(self) -> Result<String, Box<dyn Error>> {
+        if self.from_sensor {
+            return self.run_sensors();
+        }
+
+        let value: f64 = match self.value {
+            Some(value) if !self.stdin => value,
+            _ => return self.run_stdin(),
+        };
+
         // Validate value
-        let min: f64 = self.value_unit.absolute_zero();
-        if self.value < min {
-            return Err(format!(
-                "Value {} is below absolute zero for {} ({})",
-                self.value,
-                self.value_unit.full_name(),
-                min
-            )
-            .into());
+        let temperature: Temperature = self.value_unit.to_temperature(value);
+        temperature.check_absolute_zero()?;
+
+        if self.all {
+            return Ok(self.run_all(&temperature));
         }
 
         // Convert value
-        let result: f64 = self
-            .convert
-            .from_celsius(self.value_unit.to_celsius(self.value));
+        let result: Temperature = self.convert.from_temperature(&temperature);
+        let precision: usize = self.precision();
 
         Ok(format!(
-            "{}{:.2}°{} is {:.2}°{}{}",
+            "{}{:.precision$}°{} is {:.precision$}°{}{}",
             COLOR_GREEN,
-            self.value,
-            self.value_unit.full_name(),
-            result,
-            self.convert.full_name(),
+            temperature.value(),
+            temperature.full_name(),
+            result.value(),
+            result.full_name(),
             COLOR_RESET
         ))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    // Constants and helpers
-    const PACKAGE_NAME: &str = env!("CARGO_PKG_NAME");
-
-    /// Small constant to handle floating-point precision issues
-    const EPSILON: f64 = 1e-10;
-    fn assert_approx_eq(a: f64, b: f64) {
-        assert!(
-            (a - b).abs() < EPSILON,
-            "Assertion failed: {} is not approximately {}",
-            a,
-            b
-        );
+    /// Number of digits to display after the decimal point, forced to 0 when
+    /// `--round` is set
+    fn precision(&self) -> usize {
+        if self.round { 0 } else { self.precision }
     }
 
-    /// Check if the output/result string contains the expected substrings.
-    /// Ignores color code constant/strings
-    fn contains_all(output: &str, sub_strings: &[&str]) -> bool {
-        sub_strings.iter().all(|&n| output.contains(n))
+    /// Convert the value into every supported temperature scale and format
+    /// the results as a single aligned, colored report line
+    fn run_all(&self, temperature: &Temperature) -> String {
+        let precision: usize = self.precision();
+
+        let report: String = Unit::value_variants()
+            .iter()
+            .map(|unit| {
+                format!(
+                    "{:?} {:.precision$}",
+                    unit,
+                    unit.from_temperature(temperature).value()
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" / ");
+
+        format!("{}{}{}", COLOR_GREEN, report, COLOR_RESET)
     }
 
-    #[test]
-    fn test_absolute_zero_values() {
-        assert_eq!(Unit::C.absolute_zero(), ABS_ZERO_CELSIUS);
-        assert_eq!(Unit::F.absolute_zero(), ABS_ZERO_FAHRENHEIT);
-        assert_eq!(Unit::K.absolute_zero(), ABS_ZERO_KELVIN);
-    }
+    /// Read whitespace/newline-separated values from standard input and
+    /// convert each one, reporting invalid or below-absolute-zero entries
+    /// inline rather than aborting the whole stream
+    fn run_stdin(&self) -> Result<String, Box<dyn Error>> {
+        let mut input: String = String::new();
+        io::stdin().read_to_string(&mut input)?;
 
-    #[test]
-    fn test_full_names() {
-        assert_eq!(Unit::C.full_name(), "Celsius");
-        assert_eq!(Unit::F.full_name(), "Fahrenheit");
-        assert_eq!(Unit::K.full_name(), "Kelvin");
+        let lines: Vec<String> = input
+            .split_whitespace()
+            .map(|token| self.convert_token(token))
+            .collect();
+
+        Ok(lines.join("\n"))
     }
 
-    #[test]
-    fn test_to_celsius() {
-        // From Fahrenheit
-        assert_approx_eq(Unit::F.to_celsius(32.0), 0.0);
-        assert_approx_eq(Unit::F.to_celsius(212.0), 100.0);
-        assert_approx_eq(Unit::F.to_celsius(-40.0), -40.0);
+    /// Parse and convert a single stdin token, returning either a converted
+    /// result line or a colored error line
+    fn convert_token(&self, token: &str) -> String {
+        let value: f64 = match token.parse() {
+            Ok(value) => value,
+            Err(_) => return format!("{}Invalid value: {}{}", COLOR_ERROR, token, COLOR_RESET),
+        };
 
-        // From Kelvin
-        assert_approx_eq(Unit::K.to_celsius(273.15), 0.0);
-        assert_approx_eq(Unit::K.to_celsius(0.0), -273.15);
+        let temperature: Temperature = self.value_unit.to_temperature(value);
+        if let Err(error) = temperature.check_absolute_zero() {
+            return format!("{}{}{}", COLOR_ERROR, error, COLOR_RESET);
+        }
 
-        // From Celsius
-        assert_approx_eq(Unit::C.to_celsius(25.0), 25.0);
-    }
+        if self.all {
+            return self.run_all(&temperature);
+        }
 
-    #[test]
-    fn test_from_celsius() {
-        // To Fahrenheit
-        assert_approx_eq(Unit::F.from_celsius(0.0), 32.0);
-        assert_approx_eq(Unit::F.from_celsius(100.0), 212.0);
-        assert_approx_eq(Unit::F.from_celsius(-40.0), -40.0);
+        let result: Temperature = self.convert.from_temperature(&temperature);
+        let precision: usize = self.precision();
+
+        format!(
+            "{}{:.precision$}°{} is {:.precision$}°{}{}",
+            COLOR_GREEN,
+            temperature.value(),
+            temperature.full_name(),
+            result.value(),
+            result.full_name(),
+            COLOR_RESET
+        )
+    }
 
-        // To Kelvin
-        assert_approx_eq(Unit::K.from_celsius(0.0), 273.15);
-        assert_approx_eq(Unit::K.from_celsius(-273.15), 0.0);
+    /// Read every Linux thermal zone and convert each reading to the
+    /// `--convert` unit (or every scale at once, with `--all`), one line per
+    /// zone
+    fn run_sensors(&self) -> Result<String, Box<dyn Error>> {
+        let zones: Vec<(String, f64)> = sensors::read_thermal_zones();
+        if zones.is_empty() {
+            return Err("No thermal sensors found".into());
+        }
 
-        // To Celsius
-        assert_approx_eq(Unit::C.from_celsius(36.6), 36.6);
+        let precision: usize = self.precision();
+
+        let lines: Vec<String> = zones
+            .into_iter()
+            .map(|(name, celsius)| {
+                let temperature: Temperature = Temperature::Celsius(celsius);
+
+                if self.all {
+                    return format!("{}: {}", name, self.run_all(&temperature));
+                }
+
+                let result: Temperature = self.convert.from_temperature(&temperature);
+                format!(
+                    "{}{} {:.precision$}°{}{}",
+                    COLOR_GREEN,
+                    name,
+                    result.value(),
+                    result.full_name(),
+                    COLOR_RESET
+                )
+            })
+            .collect();
+
+        Ok(lines.join("\n"))
     }
+}
 
-    #[test]
-    fn test_round_trip_conversion() {
-        let original_temp: f64 = 98.6; // Body temp in Fahrenheit
-        let celsius: f64 = Unit::F.to_celsius(original_temp);
-        let back_to_f: f64 = Unit::F.from_celsius(celsius);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{ABS_ZERO_CELSIUS, ABS_ZERO_FAHRENHEIT, ABS_ZERO_KELVIN};
+
+    // Constants and helpers
+    const PACKAGE_NAME: &str = env!("CARGO_PKG_NAME");
 
-        assert_approx_eq(original_temp, back_to_f);
+    /// Check if the output/result string contains the expected substrings.
+    /// Ignores color code constant/strings
+    fn contains_all(output: &str, sub_strings: &[&str]) -> bool {
+        sub_strings.iter().all(|&n| output.contains(n))
     }
 
     // CLI/Args
     #[test]
     fn test_valid_conversion_f_to_c() {
         let args: Args = Args {
-            value: 32.0,
+            value: Some(32.0),
             value_unit: Unit::F,
             convert: Unit::C,
+            all: false,
+            stdin: false,
+            precision: 2,
+            round: false,
+            from_sensor: false,
         };
 
         let output: String = args.run().expect("Failed conversion");
         assert!(contains_all(
             &output,
-            &["32.00", Unit::F.full_name(), "0.00", Unit::C.full_name()]
+            &[
+                "32.00",
+                Temperature::Fahrenheit(0.0).full_name(),
+                "0.00",
+                Temperature::Celsius(0.0).full_name()
+            ]
         ));
     }
 
     #[test]
     fn test_valid_conversion_c_to_k() {
         let args: Args = Args {
-            value: 0.0,
+            value: Some(0.0),
             value_unit: Unit::C,
             convert: Unit::K,
+            all: false,
+            stdin: false,
+            precision: 2,
+            round: false,
+            from_sensor: false,
         };
 
         let output: String = args.run().expect("Failed conversion");
         assert!(contains_all(
             &output,
-            &["0.00", Unit::C.full_name(), "273.15", Unit::K.full_name()]
+            &[
+                "0.00",
+                Temperature::Celsius(0.0).full_name(),
+                "273.15",
+                Temperature::Kelvin(0.0).full_name()
+            ]
         ));
     }
 
     #[test]
     fn test_absolute_zero_c_error() {
         let args: Args = Args {
-            value: ABS_ZERO_CELSIUS - 1.0,
+            value: Some(ABS_ZERO_CELSIUS - 1.0),
             value_unit: Unit::C,
             convert: Unit::F,
+            all: false,
+            stdin: false,
+            precision: 2,
+            round: false,
+            from_sensor: false,
         };
 
         let output: Result<String, Box<dyn Error>> = args.run();
         assert!(output.is_err());
         let error_msg: String = output.unwrap_err().to_string();
         assert!(error_msg.contains("below absolute zero"));
-        assert!(error_msg.contains(Unit::C.full_name()));
+        assert!(error_msg.contains(Temperature::Celsius(0.0).full_name()));
         assert!(error_msg.contains(&ABS_ZERO_CELSIUS.to_string()));
     }
 
     #[test]
     fn test_absolute_zero_f_error() {
         let args: Args = Args {
-            value: ABS_ZERO_FAHRENHEIT - 1.0,
+            value: Some(ABS_ZERO_FAHRENHEIT - 1.0),
             value_unit: Unit::F,
             convert: Unit::C,
+            all: false,
+            stdin: false,
+            precision: 2,
+            round: false,
+            from_sensor: false,
         };
 
         let output: Result<String, Box<dyn Error>> = args.run();
         assert!(output.is_err());
         let error_msg: String = output.unwrap_err().to_string();
         assert!(error_msg.contains("below absolute zero"));
-        assert!(error_msg.contains(Unit::F.full_name()));
+        assert!(error_msg.contains(Temperature::Fahrenheit(0.0).full_name()));
         assert!(error_msg.contains(&ABS_ZERO_FAHRENHEIT.to_string()));
     }
 
     #[test]
     fn test_absolute_zero_k_error() {
         let args: Args = Args {
-            value: ABS_ZERO_KELVIN - 1.0,
+            value: Some(ABS_ZERO_KELVIN - 1.0),
             value_unit: Unit::K,
             convert: Unit::C,
+            all: false,
+            stdin: false,
+            precision: 2,
+            round: false,
+            from_sensor: false,
         };
 
         let output: Result<String, Box<dyn Error>> = args.run();
         assert!(output.is_err());
         let error_msg: String = output.unwrap_err().to_string();
         assert!(error_msg.contains("below absolute zero"));
-        assert!(error_msg.contains(Unit::K.full_name()));
+        assert!(error_msg.contains(Temperature::Kelvin(0.0).full_name()));
         assert!(error_msg.contains(&ABS_ZERO_KELVIN.to_string()));
     }
 
     #[test]
     fn test_negative_c_allowed() {
         let args: Args = Args {
-            value: -40.0,
+            value: Some(-40.0),
             value_unit: Unit::C,
             convert: Unit::F,
+            all: false,
+            stdin: false,
+            precision: 2,
+            round: false,
+            from_sensor: false,
         };
 
         let output: String = args
@@ -326,9 +467,14 @@ mod tests {
     #[test]
     fn test_negative_f_allowed() {
         let args: Args = Args {
-            value: -40.0,
+            value: Some(-40.0),
             value_unit: Unit::F,
             convert: Unit::C,
+            all: false,
+            stdin: false,
+            precision: 2,
+            round: false,
+            from_sensor: false,
         };
 
         let output: String = args
@@ -341,9 +487,14 @@ mod tests {
     fn test_conversion_crossover_point() {
         // -40 Celsius is -40 Fahrenheit
         let args = Args {
-            value: -40.0,
+            value: Some(-40.0),
             value_unit: Unit::C,
             convert: Unit::F,
+            all: false,
+            stdin: false,
+            precision: 2,
+            round: false,
+            from_sensor: false,
         };
 
         let output: String = args.run().expect("Failed conversion");
@@ -353,8 +504,198 @@ mod tests {
     #[test]
     fn test_parsing_defaults() {
         let args: Args = Args::parse_from([PACKAGE_NAME, "100"]);
-        assert_eq!(args.value, 100.0);
+        assert_eq!(args.value, Some(100.0));
         assert!(matches!(args.value_unit, Unit::F));
         assert!(matches!(args.convert, Unit::C));
+        assert!(!args.all);
+        assert!(!args.stdin);
+        assert_eq!(args.precision, 2);
+        assert!(!args.round);
+    }
+
+    #[test]
+    fn test_parsing_precision_flag() {
+        let args: Args = Args::parse_from([PACKAGE_NAME, "0", "--precision", "5"]);
+        assert_eq!(args.precision, 5);
+    }
+
+    #[test]
+    fn test_parsing_round_flag() {
+        let args: Args = Args::parse_from([PACKAGE_NAME, "0", "--round"]);
+        assert!(args.round);
+    }
+
+    #[test]
+    fn test_custom_precision() {
+        let args: Args = Args {
+            value: Some(0.0),
+            value_unit: Unit::C,
+            convert: Unit::F,
+            all: false,
+            stdin: false,
+            precision: 4,
+            round: false,
+            from_sensor: false,
+        };
+
+        let output: String = args.run().expect("Failed conversion");
+        assert!(output.contains("32.0000"));
+    }
+
+    #[test]
+    fn test_round_flag_emits_whole_degrees() {
+        let args: Args = Args {
+            value: Some(98.6),
+            value_unit: Unit::F,
+            convert: Unit::C,
+            all: false,
+            stdin: false,
+            precision: 2,
+            round: true,
+            from_sensor: false,
+        };
+
+        let output: String = args.run().expect("Failed conversion");
+        assert!(contains_all(&output, &["99", "37"]));
+        assert!(!output.contains("."));
+    }
+
+    #[test]
+    fn test_parsing_no_value() {
+        let args: Args = Args::parse_from([PACKAGE_NAME]);
+        assert_eq!(args.value, None);
+    }
+
+    #[test]
+    fn test_parsing_all_flag() {
+        let args: Args = Args::parse_from([PACKAGE_NAME, "0", "-u", "c", "-a"]);
+        assert!(args.all);
+    }
+
+    #[test]
+    fn test_parsing_stdin_flag() {
+        let args: Args = Args::parse_from([PACKAGE_NAME, "--stdin"]);
+        assert!(args.stdin);
+    }
+
+    #[test]
+    fn test_convert_token_valid() {
+        let args: Args = Args {
+            value: None,
+            value_unit: Unit::C,
+            convert: Unit::F,
+            all: false,
+            stdin: false,
+            precision: 2,
+            round: false,
+            from_sensor: false,
+        };
+
+        let output: String = args.convert_token("0");
+        assert!(contains_all(&output, &["0.00", "32.00"]));
+    }
+
+    #[test]
+    fn test_convert_token_invalid() {
+        let args: Args = Args {
+            value: None,
+            value_unit: Unit::C,
+            convert: Unit::F,
+            all: false,
+            stdin: false,
+            precision: 2,
+            round: false,
+            from_sensor: false,
+        };
+
+        let output: String = args.convert_token("not-a-number");
+        assert!(output.contains("Invalid value"));
+        assert!(output.contains("not-a-number"));
+    }
+
+    #[test]
+    fn test_convert_token_below_absolute_zero() {
+        let args: Args = Args {
+            value: None,
+            value_unit: Unit::C,
+            convert: Unit::F,
+            all: false,
+            stdin: false,
+            precision: 2,
+            round: false,
+            from_sensor: false,
+        };
+
+        let output: String = args.convert_token(&(ABS_ZERO_CELSIUS - 1.0).to_string());
+        assert!(output.contains("below absolute zero"));
+    }
+
+    #[test]
+    fn test_run_all_contains_every_scale() {
+        let args: Args = Args {
+            value: Some(0.0),
+            value_unit: Unit::C,
+            convert: Unit::C,
+            all: true,
+            stdin: false,
+            precision: 2,
+            round: false,
+            from_sensor: false,
+        };
+
+        let output: String = args.run().expect("Failed conversion");
+        assert!(contains_all(
+            &output,
+            &["C 0.00", "F 32.00", "K 273.15", "Ra 491.67", "Re 0.00", "De 150.00"]
+        ));
+    }
+
+    #[test]
+    fn test_parsing_from_sensor_flag() {
+        let args: Args = Args::parse_from([PACKAGE_NAME, "--from-sensor"]);
+        assert!(args.from_sensor);
+    }
+
+    #[test]
+    fn test_from_sensor_conflicts_with_value() {
+        let result = Args::try_parse_from([PACKAGE_NAME, "--from-sensor", "50"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_run_sensors_no_sensors_errors() {
+        // Sandboxed test environments typically expose no thermal zones, so
+        // this should surface an error rather than panic
+        let args: Args = Args {
+            value: None,
+            value_unit: Unit::C,
+            convert: Unit::F,
+            all: false,
+            stdin: false,
+            precision: 2,
+            round: false,
+            from_sensor: true,
+        };
+
+        if sensors::read_thermal_zones().is_empty() {
+            let output: Result<String, Box<dyn Error>> = args.run();
+            assert!(output.is_err());
+            assert!(output.unwrap_err().to_string().contains("No thermal sensors found"));
+        }
+    }
+
+    #[test]
+    fn test_from_sensor_and_all_are_compatible() {
+        // --from-sensor --all should parse (it reports every scale per zone,
+        // not just the --convert target) rather than being rejected
+        let args: Args = Args::parse_from([PACKAGE_NAME, "--from-sensor", "--all"]);
+        assert!(args.from_sensor);
+        assert!(args.all);
+
+        if sensors::read_thermal_zones().is_empty() {
+            let output: Result<String, Box<dyn Error>> = args.run();
+            assert!(output.is_err());
+            assert!(output.unwrap_err().to_string().contains("No thermal sensors found"));
+        }
     }
 }
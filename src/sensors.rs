@@ -0,0 +1,39 @@
+//! Reads live temperature readings from the Linux thermal subsystem.
+
+use std::fs;
+use std::path::PathBuf;
+
+/// Base directory exposing one subdirectory per thermal zone
+const THERMAL_CLASS_DIR: &str = "/sys/class/thermal";
+
+/// Read every available thermal zone under [`THERMAL_CLASS_DIR`], returning
+/// `(zone name, Celsius)` pairs. Zones that cannot be read are skipped.
+pub fn read_thermal_zones() -> Vec<(String, f64)> {
+    let entries = match fs::read_dir(THERMAL_CLASS_DIR) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut zones: Vec<(String, f64)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path: PathBuf = entry.path();
+            let name: &str = path.file_name()?.to_str()?;
+
+            if !name.starts_with("thermal_zone") {
+                return None;
+            }
+
+            let millidegrees: f64 = fs::read_to_string(path.join("temp"))
+                .ok()?
+                .trim()
+                .parse()
+                .ok()?;
+
+            Some((name.to_string(), millidegrees / 1000.0))
+        })
+        .collect();
+
+    zones.sort_by(|a, b| a.0.cmp(&b.0));
+    zones
+}
@@ -0,0 +1,280 @@
+//! Public, CLI-independent temperature conversion API.
+//!
+//! [`Temperature`] carries a value together with its scale, so the crate can
+//! be used as a library dependency and not just as the `temp-convert` binary.
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use crate::utils::{
+    ABS_ZERO_CELSIUS, ABS_ZERO_DELISLE, ABS_ZERO_FAHRENHEIT, ABS_ZERO_KELVIN, ABS_ZERO_RANKINE,
+    ABS_ZERO_REAUMUR,
+};
+
+/// A temperature value paired with its scale
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Temperature {
+    Celsius(f64),
+    Fahrenheit(f64),
+    Kelvin(f64),
+    Rankine(f64),
+    Reaumur(f64),
+    Delisle(f64),
+}
+
+/// Returned when a [`Temperature`] value is below absolute zero for its scale
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemperatureError {
+    value: f64,
+    unit: &'static str,
+    min: f64,
+}
+
+impl fmt::Display for TemperatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Value {} is below absolute zero for {} ({})",
+            self.value, self.unit, self.min
+        )
+    }
+}
+
+impl Error for TemperatureError {}
+
+impl Temperature {
+    /// The raw numeric value, independent of scale
+    pub fn value(&self) -> f64 {
+        match self {
+            Temperature::Celsius(v)
+            | Temperature::Fahrenheit(v)
+            | Temperature::Kelvin(v)
+            | Temperature::Rankine(v)
+            | Temperature::Reaumur(v)
+            | Temperature::Delisle(v) => *v,
+        }
+    }
+
+    /// Full scale name, e.g. "Celsius"
+    pub fn full_name(&self) -> &'static str {
+        match self {
+            Temperature::Celsius(_) => "Celsius",
+            Temperature::Fahrenheit(_) => "Fahrenheit",
+            Temperature::Kelvin(_) => "Kelvin",
+            Temperature::Rankine(_) => "Rankine",
+            Temperature::Reaumur(_) => "Réaumur",
+            Temperature::Delisle(_) => "Delisle",
+        }
+    }
+
+    /// Absolute zero expressed in this value's own scale
+    pub fn absolute_zero(&self) -> f64 {
+        match self {
+            Temperature::Celsius(_) => ABS_ZERO_CELSIUS,
+            Temperature::Fahrenheit(_) => ABS_ZERO_FAHRENHEIT,
+            Temperature::Kelvin(_) => ABS_ZERO_KELVIN,
+            Temperature::Rankine(_) => ABS_ZERO_RANKINE,
+            Temperature::Reaumur(_) => ABS_ZERO_REAUMUR,
+            Temperature::Delisle(_) => ABS_ZERO_DELISLE,
+        }
+    }
+
+    /// The value expressed in Celsius, regardless of the original scale
+    fn celsius_value(&self) -> f64 {
+        match self {
+            Temperature::Celsius(v) => *v,
+            Temperature::Fahrenheit(v) => (v - 32.0) * 5.0 / 9.0,
+            Temperature::Kelvin(v) => v - 273.15,
+            Temperature::Rankine(v) => (v - 491.67) * 5.0 / 9.0,
+            Temperature::Reaumur(v) => v * 5.0 / 4.0,
+            Temperature::Delisle(v) => 100.0 - (v * 2.0 / 3.0),
+        }
+    }
+
+    /// Convert to Celsius
+    pub fn to_celsius(&self) -> Temperature {
+        Temperature::Celsius(self.celsius_value())
+    }
+
+    /// Convert to Fahrenheit
+    pub fn to_fahrenheit(&self) -> Temperature {
+        Temperature::Fahrenheit((self.celsius_value() * 9.0 / 5.0) + 32.0)
+    }
+
+    /// Convert to Kelvin
+    pub fn to_kelvin(&self) -> Temperature {
+        Temperature::Kelvin(self.celsius_value() + 273.15)
+    }
+
+    /// Convert to Rankine
+    pub fn to_rankine(&self) -> Temperature {
+        Temperature::Rankine((self.celsius_value() * 9.0 / 5.0) + 491.67)
+    }
+
+    /// Convert to Réaumur
+    pub fn to_reaumur(&self) -> Temperature {
+        Temperature::Reaumur(self.celsius_value() * 4.0 / 5.0)
+    }
+
+    /// Convert to Delisle
+    pub fn to_delisle(&self) -> Temperature {
+        Temperature::Delisle((100.0 - self.celsius_value()) * 3.0 / 2.0)
+    }
+
+    /// Check that this value is physically valid, i.e. not below absolute
+    /// zero for its scale
+    pub fn check_absolute_zero(&self) -> Result<(), TemperatureError> {
+        let min: f64 = self.absolute_zero();
+        // Delisle runs backwards: colder temperatures have larger values
+        let below_min: bool = match self {
+            Temperature::Delisle(v) => *v > min,
+            _ => self.value() < min,
+        };
+        if below_min {
+            return Err(TemperatureError {
+                value: self.value(),
+                unit: self.full_name(),
+                min,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Temperature> for f64 {
+    fn from(temperature: Temperature) -> f64 {
+        temperature.value()
+    }
+}
+
+impl TryFrom<f64> for Temperature {
+    type Error = TemperatureError;
+
+    /// Builds a Celsius [`Temperature`], rejecting values below absolute zero
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        let temperature: Temperature = Temperature::Celsius(value);
+        temperature.check_absolute_zero()?;
+        Ok(temperature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-10;
+    fn assert_approx_eq(a: f64, b: f64) {
+        assert!(
+            (a - b).abs() < EPSILON,
+            "Assertion failed: {} is not approximately {}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn test_value_and_full_name() {
+        let temperature: Temperature = Temperature::Fahrenheit(98.6);
+        assert_approx_eq(temperature.value(), 98.6);
+        assert_eq!(temperature.full_name(), "Fahrenheit");
+    }
+
+    #[test]
+    fn test_absolute_zero() {
+        assert_eq!(Temperature::Celsius(0.0).absolute_zero(), ABS_ZERO_CELSIUS);
+        assert_eq!(
+            Temperature::Fahrenheit(0.0).absolute_zero(),
+            ABS_ZERO_FAHRENHEIT
+        );
+        assert_eq!(Temperature::Kelvin(0.0).absolute_zero(), ABS_ZERO_KELVIN);
+    }
+
+    #[test]
+    fn test_to_celsius() {
+        // From Fahrenheit
+        assert_approx_eq(Temperature::Fahrenheit(32.0).to_celsius().value(), 0.0);
+        assert_approx_eq(Temperature::Fahrenheit(212.0).to_celsius().value(), 100.0);
+        assert_approx_eq(Temperature::Fahrenheit(-40.0).to_celsius().value(), -40.0);
+
+        // From Kelvin
+        assert_approx_eq(Temperature::Kelvin(273.15).to_celsius().value(), 0.0);
+        assert_approx_eq(Temperature::Kelvin(0.0).to_celsius().value(), -273.15);
+
+        // From Rankine
+        assert_approx_eq(Temperature::Rankine(491.67).to_celsius().value(), 0.0);
+        assert_approx_eq(Temperature::Rankine(0.0).to_celsius().value(), -273.15);
+
+        // From Réaumur
+        assert_approx_eq(Temperature::Reaumur(80.0).to_celsius().value(), 100.0);
+        assert_approx_eq(Temperature::Reaumur(0.0).to_celsius().value(), 0.0);
+
+        // From Delisle
+        assert_approx_eq(Temperature::Delisle(150.0).to_celsius().value(), 0.0);
+        assert_approx_eq(Temperature::Delisle(0.0).to_celsius().value(), 100.0);
+
+        // From Celsius
+        assert_approx_eq(Temperature::Celsius(25.0).to_celsius().value(), 25.0);
+    }
+
+    #[test]
+    fn test_from_celsius() {
+        // To Fahrenheit
+        assert_approx_eq(Temperature::Celsius(0.0).to_fahrenheit().value(), 32.0);
+        assert_approx_eq(Temperature::Celsius(100.0).to_fahrenheit().value(), 212.0);
+        assert_approx_eq(Temperature::Celsius(-40.0).to_fahrenheit().value(), -40.0);
+
+        // To Kelvin
+        assert_approx_eq(Temperature::Celsius(0.0).to_kelvin().value(), 273.15);
+        assert_approx_eq(Temperature::Celsius(-273.15).to_kelvin().value(), 0.0);
+
+        // To Rankine
+        assert_approx_eq(Temperature::Celsius(0.0).to_rankine().value(), 491.67);
+        assert_approx_eq(Temperature::Celsius(-273.15).to_rankine().value(), 0.0);
+
+        // To Réaumur
+        assert_approx_eq(Temperature::Celsius(100.0).to_reaumur().value(), 80.0);
+        assert_approx_eq(Temperature::Celsius(0.0).to_reaumur().value(), 0.0);
+
+        // To Delisle
+        assert_approx_eq(Temperature::Celsius(100.0).to_delisle().value(), 0.0);
+        assert_approx_eq(Temperature::Celsius(0.0).to_delisle().value(), 150.0);
+
+        // To Celsius
+        assert_approx_eq(Temperature::Celsius(36.6).to_celsius().value(), 36.6);
+    }
+
+    #[test]
+    fn test_round_trip_conversion() {
+        let original: Temperature = Temperature::Fahrenheit(98.6); // Body temp
+        let celsius: Temperature = original.to_celsius();
+        let back_to_f: Temperature = celsius.to_fahrenheit();
+
+        assert_approx_eq(original.value(), back_to_f.value());
+    }
+
+    #[test]
+    fn test_check_absolute_zero_err() {
+        let temperature: Temperature = Temperature::Celsius(ABS_ZERO_CELSIUS - 1.0);
+        let error: TemperatureError = temperature.check_absolute_zero().unwrap_err();
+        let message: String = error.to_string();
+        assert!(message.contains("below absolute zero"));
+        assert!(message.contains("Celsius"));
+    }
+
+    #[test]
+    fn test_from_temperature_for_f64() {
+        let value: f64 = f64::from(Temperature::Kelvin(273.15));
+        assert_approx_eq(value, 273.15);
+    }
+
+    #[test]
+    fn test_try_from_f64() {
+        let temperature: Temperature = Temperature::try_from(25.0).expect("valid Celsius value");
+        assert!(matches!(temperature, Temperature::Celsius(v) if v == 25.0));
+
+        let error: TemperatureError = Temperature::try_from(ABS_ZERO_CELSIUS - 1.0).unwrap_err();
+        assert!(error.to_string().contains("below absolute zero"));
+    }
+}
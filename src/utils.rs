@@ -1,6 +1,9 @@
 pub const ABS_ZERO_CELSIUS: f64 = -273.15;
 pub const ABS_ZERO_FAHRENHEIT: f64 = -459.67;
 pub const ABS_ZERO_KELVIN: f64 = 0.0;
+pub const ABS_ZERO_RANKINE: f64 = 0.0;
+pub const ABS_ZERO_REAUMUR: f64 = -218.52;
+pub const ABS_ZERO_DELISLE: f64 = 559.725;
 
 pub const COLOR_GREEN: &str = "\x1b[32m";
 pub const COLOR_ERROR: &str = "\x1b[31m";